@@ -48,6 +48,212 @@ where F: Fn(&T1, *const T2, usize) -> Ordering,
     return not_found_value;
 }
 
+/// A branchless sibling of [`bsearch`]. It keeps the same raw-pointer / `compare_f` contract,
+/// but always runs the same number of iterations for a given `data_length`, regardless of
+/// where (or whether) `key` is found. This makes the loop count predictable, which helps the
+/// branch predictor on hot lookup paths and gives more uniform timing than the early-exit
+/// `bsearch`. ^^
+///
+/// # Arguments
+///
+/// * `key` - Value to search for
+/// * `data_ptr` - Constant raw pointer to the array. You can get it using 'data_array.as_ptr()'
+/// * `data_length` - Array size. It can be lower than the array capacity.
+/// * `not_found_value` - Value to return if value not found
+/// * `compare_f` - Function used to compare^^
+///
+/// # Notes
+/// ^^ This `not_found_value` will limit the possible lenght by -1 element. For example, if
+/// the platform is based on 32bits (like wasm), the recommended value for this is the max
+/// value of u32 (usize): 0xFFFF_FFFF
+///
+/// # Example
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use rselib::sort::bsearch_branchless;
+///
+/// let test_array: [u8; 3] = [0x10, 0x20, 0x30];
+/// let test_array_ptr = test_array.as_ptr() as *const u8;
+/// let length = test_array.len();
+/// let not_found_value: usize = 0xFFFF_FFFF;
+///
+/// let found_index = bsearch_branchless(
+///     0x20
+///     , test_array_ptr
+///     , length
+///     , not_found_value
+///     , |key, ptr, index| {
+///         let current_value = unsafe {
+///             & *(
+///                 ptr.add(index)
+///             )
+///         };
+///         if *key == *current_value {
+///             return Ordering::Equal
+///         } else if *key > *current_value {
+///             return Ordering::Greater;
+///         } else {
+///             return Ordering::Less;
+///         }
+///     }
+/// );
+/// assert_eq!(1, found_index);
+/// ```
+///
+pub fn bsearch_branchless<T1, T2, F>(
+    key: T1
+    , data_ptr: *const T2
+    , data_length: usize
+    , not_found_value: usize
+    , compare_f: F
+) -> usize
+where F: Fn(&T1, *const T2, usize) -> Ordering,
+{
+    if data_length == 0 {
+        return not_found_value;
+    }
+    let mut base: usize = 0;
+    let mut size: usize = data_length;
+    while size > 1 {
+        let half = size >> 1;
+        let mid = base + half;
+        let go_right = compare_f(&key, data_ptr, mid) != Ordering::Less;
+        base = [base, mid][go_right as usize];
+        size -= half;
+    }
+    if compare_f(&key, data_ptr, base) == Ordering::Equal {
+        return base;
+    }
+    return not_found_value;
+}
+
+fn eytzinger_build_rec<T>(
+    src_ptr: *const T
+    , dst_ptr: *mut T
+    , len: usize
+    , k: usize
+    , next_sorted: &mut usize
+)
+where T: Copy,
+{
+    if k < len {
+        eytzinger_build_rec(src_ptr, dst_ptr, len, 2*k + 1, next_sorted);
+        unsafe {
+            *dst_ptr.add(k) = *src_ptr.add(*next_sorted);
+        }
+        *next_sorted += 1;
+        eytzinger_build_rec(src_ptr, dst_ptr, len, 2*k + 2, next_sorted);
+    }
+}
+
+/// Rewrite a sorted array into [Eytzinger order](https://en.wikipedia.org/wiki/Eytzinger_layout)
+/// (a BFS / implicit-heap layout): a 1-indexed complete binary tree stored in a 0-indexed array,
+/// where for slot `i` the left child lives at `2*i+1` and the right child at `2*i+2`. Searching
+/// this layout with [`eytzinger_search`] visits far fewer cache lines than [`bsearch`] on the
+/// same data, since consecutive probes stay close together in memory, which matters when the
+/// same static table is probed millions of times.
+///
+/// # Arguments
+///
+/// * `src_ptr` - Constant raw pointer to the sorted source array. You can get it using
+///   'data_array.as_ptr()'
+/// * `dst_ptr` - Mutable raw pointer to the destination array that will hold the Eytzinger
+///   layout. It must have room for `len` elements and must not alias `src_ptr`.
+/// * `len` - Number of elements to rewrite.
+///
+/// # Notes
+/// `src_ptr` is read exactly once per element (in sorted order) and `dst_ptr` is written exactly
+/// once per element (in Eytzinger order), so the two buffers may have any relationship to each
+/// other except aliasing.
+pub fn eytzinger_build<T>(
+    src_ptr: *const T
+    , dst_ptr: *mut T
+    , len: usize
+)
+where T: Copy,
+{
+    let mut next_sorted: usize = 0;
+    eytzinger_build_rec(src_ptr, dst_ptr, len, 0, &mut next_sorted);
+}
+
+/// Search an array previously rewritten by [`eytzinger_build`] for `key`, returning
+/// `not_found_value` if not found.
+///
+/// # Arguments
+///
+/// * `key` - Value to search for
+/// * `data_ptr` - Constant raw pointer to the Eytzinger-ordered array. You can get it using
+///   'data_array.as_ptr()'
+/// * `len` - Array size. It can be lower than the array capacity.
+/// * `not_found_value` - Value to return if value not found
+/// * `compare_f` - Function used to compare^^
+///
+/// # Notes
+/// ^^ This `not_found_value` will limit the possible lenght by -1 element. For example, if
+/// the platform is based on 32bits (like wasm), the recommended value for this is the max
+/// value of u32 (usize): 0xFFFF_FFFF
+///
+/// # Example
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use rselib::sort::{eytzinger_build, eytzinger_search};
+///
+/// let sorted_array: [u8; 7] = [0x10, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70];
+/// let mut eytzinger_array: [u8; 7] = [0; 7];
+///
+/// eytzinger_build(
+///     sorted_array.as_ptr()
+///     , eytzinger_array.as_mut_ptr()
+///     , sorted_array.len()
+/// );
+///
+/// let not_found_value: usize = 0xFFFF_FFFF;
+/// let found_index = eytzinger_search(
+///     0x40
+///     , eytzinger_array.as_ptr()
+///     , eytzinger_array.len()
+///     , not_found_value
+///     , |key, ptr, index| {
+///         let current_value = unsafe {
+///             & *(
+///                 ptr.add(index)
+///             )
+///         };
+///         if *key == *current_value {
+///             return Ordering::Equal
+///         } else if *key > *current_value {
+///             return Ordering::Greater;
+///         } else {
+///             return Ordering::Less;
+///         }
+///     }
+/// );
+/// assert_eq!(eytzinger_array[found_index], 0x40);
+/// ```
+///
+pub fn eytzinger_search<T1, T2, F>(
+    key: T1
+    , data_ptr: *const T2
+    , len: usize
+    , not_found_value: usize
+    , compare_f: F
+) -> usize
+where F: Fn(&T1, *const T2, usize) -> Ordering,
+{
+    let mut k: usize = 1;
+    while k <= len {
+        let go_right = compare_f(&key, data_ptr, k - 1) == Ordering::Greater;
+        k = 2*k + (go_right as usize);
+    }
+    let candidate = k >> (usize::trailing_ones(k) + 1);
+    if candidate >= 1 && candidate <= len && compare_f(&key, data_ptr, candidate - 1) == Ordering::Equal {
+        return candidate - 1;
+    }
+    return not_found_value;
+}
+
 /// An `AproxBinarySearchResult` is how the `aprox_bsearch` ended:
 /// * `ExactMatchIndex` - The value was found in the array
 /// * `AproxMatch` - An index was found inside the current array
@@ -156,6 +362,206 @@ where F: Fn(&T1, *const T2, usize) -> Ordering,
     } 
 }
 
+/// Find the first index whose element is `>= key`. Unlike [`aprox_bsearch`], this never
+/// early-exits on `Ordering::Equal`, so it is safe on arrays containing duplicate keys: it
+/// always returns the *first* matching index rather than an arbitrary one.
+///
+/// # Arguments
+///
+/// * `key` - Value to search for
+/// * `data_ptr` - Constant raw pointer to the array. You can get it using 'data_array.as_ptr()'
+/// * `data_length` - Array size. It can be lower than the array capacity.
+/// * `compare_f` - Function used to compare^^
+///
+/// # Notes
+/// ^^ The use of this function is to compare a key vs a complex type that contains a key.
+pub fn lower_bound<T1, T2, F>(
+    key: T1
+    , data_ptr: *const T2
+    , data_length: usize
+    , compare_f: F
+) -> usize
+where F: Fn(&T1, *const T2, usize) -> Ordering,
+{
+    let mut left = 0;
+    let mut right = data_length;
+    while left < right {
+        let mid = left + ((right - left) >> 1);
+        if compare_f(&key, data_ptr, mid) == Ordering::Greater {
+            left = mid + 1;
+        } else {
+            right = mid;
+        }
+    }
+    return left;
+}
+
+/// Find the first index whose element is `> key`. Together with [`lower_bound`] this delimits
+/// the equal-range of `key` inside the array (see [`equal_range`]).
+///
+/// # Arguments
+///
+/// * `key` - Value to search for
+/// * `data_ptr` - Constant raw pointer to the array. You can get it using 'data_array.as_ptr()'
+/// * `data_length` - Array size. It can be lower than the array capacity.
+/// * `compare_f` - Function used to compare^^
+///
+/// # Notes
+/// ^^ The use of this function is to compare a key vs a complex type that contains a key.
+pub fn upper_bound<T1, T2, F>(
+    key: T1
+    , data_ptr: *const T2
+    , data_length: usize
+    , compare_f: F
+) -> usize
+where F: Fn(&T1, *const T2, usize) -> Ordering,
+{
+    let mut left = 0;
+    let mut right = data_length;
+    while left < right {
+        let mid = left + ((right - left) >> 1);
+        if compare_f(&key, data_ptr, mid) == Ordering::Less {
+            right = mid;
+        } else {
+            left = mid + 1;
+        }
+    }
+    return left;
+}
+
+/// Find the half-open range `[start, end)` of indexes whose element equals `key`, i.e.
+/// `(lower_bound(key), upper_bound(key))`. `start == end` means `key` is not present, and
+/// `start` is then its would-be insertion point.
+///
+/// # Arguments
+///
+/// * `key` - Value to search for
+/// * `data_ptr` - Constant raw pointer to the array. You can get it using 'data_array.as_ptr()'
+/// * `data_length` - Array size. It can be lower than the array capacity.
+/// * `compare_f` - Function used to compare^^
+///
+/// # Notes
+/// ^^ The use of this function is to compare a key vs a complex type that contains a key.
+///
+/// # Example
+///
+/// Given a sorted array with repeated keys \[0x10, 0x10, 0x20], find the equal-range of 0x10.
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use rselib::sort::equal_range;
+///
+/// let test_array: [u8; 3] = [0x10, 0x10, 0x20];
+/// let test_array_ptr = test_array.as_ptr() as *const u8;
+/// let length = test_array.len();
+/// let (start, end) = equal_range(
+///     0x10
+///     , test_array_ptr
+///     , length
+///     , |key, ptr, index| {
+///         let current_value = unsafe {
+///             & *(
+///                 ptr.add(index)
+///             )
+///         };
+///         if *key == *current_value {
+///             return Ordering::Equal
+///         } else if *key > *current_value {
+///             return Ordering::Greater;
+///         } else {
+///             return Ordering::Less;
+///         }
+///     }
+/// );
+/// assert_eq!(0, start);
+/// assert_eq!(2, end);
+/// ```
+pub fn equal_range<T1, T2, F>(
+    key: T1
+    , data_ptr: *const T2
+    , data_length: usize
+    , compare_f: F
+) -> (usize, usize)
+where T1: Copy, F: Fn(&T1, *const T2, usize) -> Ordering,
+{
+    let start = lower_bound(key, data_ptr, data_length, &compare_f);
+    let end = upper_bound(key, data_ptr, data_length, &compare_f);
+    return (start, end);
+}
+
+/// Locate the equal-range of `key` (via [`lower_bound`]/[`upper_bound`]) and invoke `action_f`
+/// for every matching index, in order, stopping early as soon as `action_f` returns `false`.
+/// This fuses the search with the action over its hits, so callers implementing "return first",
+/// "find all" or a reduction (max/min/count) over duplicate keys don't need to allocate an
+/// intermediate index list or pay for a second search pass.
+///
+/// # Arguments
+///
+/// * `key` - Value to search for
+/// * `data_ptr` - Constant raw pointer to the array. You can get it using 'data_array.as_ptr()'
+/// * `data_length` - Array size. It can be lower than the array capacity.
+/// * `compare_f` - Function used to compare^^
+/// * `action_f` - Function invoked for each matching index; return `false` to stop early.
+///
+/// # Notes
+/// ^^ The use of this function is to compare a key vs a complex type that contains a key.
+///
+/// # Example
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use rselib::sort::find_action;
+///
+/// let test_array: [u8; 4] = [0x10, 0x20, 0x20, 0x30];
+/// let test_array_ptr = test_array.as_ptr() as *const u8;
+/// let length = test_array.len();
+///
+/// let mut count: usize = 0;
+/// find_action(
+///     0x20
+///     , test_array_ptr
+///     , length
+///     , |key, ptr, index| {
+///         let current_value = unsafe {
+///             & *(
+///                 ptr.add(index)
+///             )
+///         };
+///         if *key == *current_value {
+///             return Ordering::Equal
+///         } else if *key > *current_value {
+///             return Ordering::Greater;
+///         } else {
+///             return Ordering::Less;
+///         }
+///     }
+///     , |_ptr, _index| {
+///         count += 1;
+///         true
+///     }
+/// );
+/// assert_eq!(2, count);
+/// ```
+pub fn find_action<T1, T2, F, A>(
+    key: T1
+    , data_ptr: *const T2
+    , data_length: usize
+    , compare_f: F
+    , action_f: A
+)
+where T1: Copy, F: Fn(&T1, *const T2, usize) -> Ordering, A: FnMut(*const T2, usize) -> bool,
+{
+    let (start, end) = equal_range(key, data_ptr, data_length, compare_f);
+    let mut action_f = action_f;
+    let mut index = start;
+    while index < end {
+        if !action_f(data_ptr, index) {
+            break;
+        }
+        index += 1;
+    }
+}
+
 #[derive(Debug)]
 pub enum SortedArrayAllocResult {
     Ok
@@ -296,53 +702,706 @@ F2: Fn(*mut T2, usize, usize)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use core::cmp::Ordering;
-
-    /// Function to compare u8's used in aprox_bsearch
-    fn u8_cmp(
-        key: &u8
-        , ptr: *const u8
-        , index: usize
-    ) -> Ordering {
-        let current_value = unsafe {
-            & *(
-                ptr.add(index)
-            )
-        };
-
-        if *key == *current_value {
-            return Ordering::Equal
-        } else if *key > *current_value {
-            return Ordering::Greater;
-        } else {
-            return Ordering::Less;
-        }
-    }
-
-    fn u8_cp(
-        ptr: *mut u8
-        , src_index: usize
-        , dest_index: usize
-    ) {
-        let src = unsafe {
-            & *(
-                ptr.add(src_index)
-            )
-        };
-        let dest = unsafe {
-            &mut *(
-                ptr.add(dest_index)
-            )
-        };
-        *dest = *src;
-    }
+#[derive(Debug)]
+pub enum RemoveResult {
+    Ok
+    , NotFound
+}
 
-    #[test]
-    fn insert_at_the_end() {
-        let mut test_array: [u8; 3] = [0x10, 0x20, 0x00]; // pre allocated array
+/// For a pre allocated array, the sorted_array_remove will shift the contents to the left if
+/// the key exists in it, returning the freed index and the number of removed elements (always
+/// `1` on success) so the caller can decrement its length. Mirrors [`sorted_array_insert`] to
+/// complete the sorted-array maintenance API used in fixed-capacity symbol/lookup tables on
+/// microcontrollers, where entries must be retired as well as added.
+///
+/// # Arguments
+///
+/// * `key` - Value to search for
+/// * `data_ptr` - Mutable raw pointer to the array. You can get it using 'data_array.as_mut_ptr()'
+/// * `data_original_length` - Array size. It can be lower than the array capacity
+/// * `compare_f` - Function used to compare^^
+/// * `copy_f` - Function to shift elements along the array
+///
+/// # Example
+/// ```
+/// use core::cmp::Ordering;
+/// use rselib::sort::{sorted_array_remove, RemoveResult};
+///
+/// let mut test_array: [u8; 3] = [0x10, 0x20, 0x30]; // pre allocated array
+///
+/// let test_array_ptr = test_array.as_mut_ptr();
+/// let mut length = 3; // Used elements in the pre allocated array
+///
+/// let (res, removed_index, removed_count) = sorted_array_remove(
+///     0x20
+///     , test_array_ptr
+///     , length
+///     , |key, ptr, index| {
+///         let current_value = unsafe {
+///             & *(
+///                 ptr.add(index)
+///             )
+///         };
+///         if *key == *current_value {
+///             return Ordering::Equal
+///         } else if *key > *current_value {
+///             return Ordering::Greater;
+///         } else {
+///             return Ordering::Less;
+///         }
+///     }, |ptr, src_index, dest_index | {
+///         let src = unsafe {
+///             & *(
+///                 ptr.add(src_index)
+///             )
+///         };
+///         let dest = unsafe {
+///             &mut *(
+///                 ptr.add(dest_index)
+///             )
+///         };
+///         *dest = *src;
+///     }
+/// );
+/// assert!(matches!(res, RemoveResult::Ok));
+/// assert_eq!(1, removed_index);
+/// assert_eq!(1, removed_count);
+///
+/// // !Update length
+/// length -= removed_count;
+///
+/// assert_eq!(0x10, test_array[0]);
+/// assert_eq!(0x30, test_array[1]);
+/// assert_eq!(2, length);
+/// ```
+pub fn sorted_array_remove<T1, T2, F1, F2>(
+    key: T1
+    , data_ptr: *mut T2
+    , data_original_length: usize
+    , compare_f: F1
+    , copy_f: F2
+) -> (RemoveResult, usize, usize)
+where F1: Fn(&T1, *const T2, usize) -> Ordering,
+F2: Fn(*mut T2, usize, usize)
+{
+    let (aprox_result, possible_index) = aprox_bsearch(
+        key
+        , data_ptr
+        , data_original_length
+        , compare_f
+    );
+    match aprox_result {
+        AproxBinarySearchResult::ExactMatchIndex => {
+            let mut target_index = possible_index;
+            while target_index + 1 < data_original_length {
+                copy_f(
+                    data_ptr
+                    , target_index + 1
+                    , target_index
+                );
+                target_index += 1;
+            }
+            return (RemoveResult::Ok, possible_index, 1);
+        },
+        _ => {
+            return (RemoveResult::NotFound, possible_index, 0);
+        },
+    }
+}
+
+/// A lightweight, fixed-capacity handle around a caller-provided buffer that remembers whether
+/// it is still sorted. It borrows the buffer (no allocation, still `no_std`), letting callers
+/// batch many cheap [`push_unsorted`](SortedArray::push_unsorted) appends and amortize the cost
+/// of sorting over [`search`](SortedArray::search)/[`contains`](SortedArray::contains), instead
+/// of paying an insertion shift on every element via [`insert`](SortedArray::insert). The raw
+/// pointer / `compare_f` core (`bsearch`, `sorted_array_insert`) is unchanged underneath.
+pub struct SortedArray<T> {
+    data_ptr: *mut T
+    , length: usize
+    , capacity: usize
+    , is_sorted: bool
+}
+
+impl<T> SortedArray<T>
+where T: Copy,
+{
+    /// Wrap a buffer of `capacity` elements, `length` of which are already in use. `is_sorted`
+    /// tells the handle whether those `length` elements are already in sorted order.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_ptr` - Mutable raw pointer to the buffer. You can get it using
+    ///   'data_array.as_mut_ptr()'
+    /// * `length` - Used elements in the buffer.
+    /// * `capacity` - Real allocated buffer length.
+    /// * `is_sorted` - Whether the `length` used elements are already sorted.
+    pub fn new(
+        data_ptr: *mut T
+        , length: usize
+        , capacity: usize
+        , is_sorted: bool
+    ) -> Self {
+        SortedArray {
+            data_ptr
+            , length
+            , capacity
+            , is_sorted
+        }
+    }
+
+    /// Used elements in the buffer.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Whether the buffer has no used elements.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Total number of elements the underlying buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of additional elements that can be appended before the buffer is full.
+    pub fn remaining(&self) -> usize {
+        self.capacity - self.length
+    }
+
+    /// Drop every used element without touching the underlying buffer's contents.
+    pub fn clear(&mut self) {
+        self.length = 0;
+        self.is_sorted = true;
+    }
+
+    /// Append `value` at the end in O(1), without preserving order, and mark the array dirty
+    /// so the next [`search`](SortedArray::search)/[`contains`](SortedArray::contains) re-sorts
+    /// it first. Returns `false` if the buffer is already at capacity.
+    pub fn push_unsorted(&mut self, value: T) -> bool {
+        if self.length >= self.capacity {
+            return false;
+        }
+        unsafe {
+            *self.data_ptr.add(self.length) = value;
+        }
+        self.length += 1;
+        self.is_sorted = false;
+        return true;
+    }
+
+    /// Insert `key` keeping the array sorted, via [`sorted_array_insert`], when the array is
+    /// already known to be sorted. If it is currently dirty (after a `push_unsorted`), this
+    /// does a linear scan over the unsorted elements first so the "no duplicate keys" contract
+    /// still holds, then appends via `push_unsorted` instead of paying to re-sort on every call.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Value to insert
+    /// * `not_allocated_value` - Value to return as the index if the capacity was exceeded
+    /// * `compare_f` - Function used to compare^^
+    /// * `copy_f` - Function to shift elements along the array
+    pub fn insert<F1, F2>(
+        &mut self
+        , key: T
+        , not_allocated_value: usize
+        , compare_f: F1
+        , copy_f: F2
+    ) -> (SortedArrayAllocResult, usize, usize)
+    where F1: Fn(&T, *const T, usize) -> Ordering, F2: Fn(*mut T, usize, usize),
+    {
+        if !self.is_sorted {
+            for index in 0..self.length {
+                if compare_f(&key, self.data_ptr, index) == Ordering::Equal {
+                    return (SortedArrayAllocResult::Ok, index, 0);
+                }
+            }
+            if self.push_unsorted(key) {
+                return (SortedArrayAllocResult::Ok, self.length - 1, 1);
+            }
+            return (SortedArrayAllocResult::ArrayCapacityExceeded, not_allocated_value, 0);
+        }
+        let (res, index, added_count) = sorted_array_insert(
+            key
+            , self.data_ptr
+            , self.length
+            , self.capacity
+            , not_allocated_value
+            , compare_f
+            , copy_f
+        );
+        if let SortedArrayAllocResult::Ok = res {
+            // `added_count == 0` means `sorted_array_insert` found an exact match and left the
+            // array untouched; writing `key` here would silently clobber the existing entry,
+            // which is wrong for any `T` where the key doesn't fully determine equality.
+            if added_count > 0 {
+                unsafe {
+                    *self.data_ptr.add(index) = key;
+                }
+            }
+            self.length += added_count;
+        }
+        return (res, index, added_count);
+    }
+
+    /// Search for `key`, lazily re-sorting the buffer first (via `sort_f`) if it was left
+    /// dirty by `push_unsorted`, then delegating to [`bsearch`].
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Value to search for
+    /// * `not_found_value` - Value to return if value not found
+    /// * `compare_f` - Function used to compare^^
+    /// * `sort_f` - Function used to sort the buffer's `length` used elements in place
+    pub fn search<F1, F2>(
+        &mut self
+        , key: T
+        , not_found_value: usize
+        , compare_f: F1
+        , sort_f: F2
+    ) -> usize
+    where F1: Fn(&T, *const T, usize) -> Ordering, F2: FnOnce(*mut T, usize),
+    {
+        if !self.is_sorted {
+            sort_f(self.data_ptr, self.length);
+            self.is_sorted = true;
+        }
+        return bsearch(key, self.data_ptr, self.length, not_found_value, compare_f);
+    }
+
+    /// Whether `key` is present, lazily re-sorting the buffer first (via `sort_f`) if needed.
+    pub fn contains<F1, F2>(
+        &mut self
+        , key: T
+        , compare_f: F1
+        , sort_f: F2
+    ) -> bool
+    where F1: Fn(&T, *const T, usize) -> Ordering, F2: FnOnce(*mut T, usize),
+    {
+        let not_found_value: usize = usize::MAX;
+        return self.search(key, not_found_value, compare_f, sort_f) != not_found_value;
+    }
+}
+
+const LO_U8: usize = usize::MAX / 0xFF;
+const HI_U8: usize = LO_U8 * 0x80;
+
+/// Scan a `u8` buffer a machine word at a time looking for `key`, returning `not_found_value`
+/// if absent. For the short lookup tables common in embedded parsers, the branch and
+/// pointer-chasing cost of a binary search loses to a straight scan: `key` is broadcast into
+/// every byte lane of a `usize`, each word-sized chunk (read with `read_unaligned`) is XORed
+/// against it, and the classic zero-byte-detection trick `(x.wrapping_sub(lo)) & !x & hi`
+/// tells in one step whether any lane matches; only then are that chunk's bytes checked one by
+/// one for the exact index. The tail (fewer than `size_of::<usize>()` bytes) is then checked
+/// byte-wise.
+///
+/// # Arguments
+///
+/// * `key` - Value to search for
+/// * `data_ptr` - Constant raw pointer to the array. You can get it using 'data_array.as_ptr()'
+/// * `data_length` - Array size. It can be lower than the array capacity.
+/// * `not_found_value` - Value to return if value not found^^
+///
+/// # Notes
+/// ^^ This `not_found_value` will limit the possible lenght by -1 element. For example, if
+/// the platform is based on 32bits (like wasm), the recommended value for this is the max
+/// value of u32 (usize): 0xFFFF_FFFF
+///
+/// # Safety
+/// `data_ptr` must be valid for reads of `data_length` bytes: this function reads the buffer
+/// directly rather than through a caller-supplied `compare_f` closure.
+pub unsafe fn search_small_u8(
+    key: u8
+    , data_ptr: *const u8
+    , data_length: usize
+    , not_found_value: usize
+) -> usize
+{
+    let word_size = core::mem::size_of::<usize>();
+    let broadcast = (key as usize) * LO_U8;
+    let mut index = 0;
+    while index + word_size <= data_length {
+        let chunk = unsafe {
+            core::ptr::read_unaligned(data_ptr.add(index) as *const usize)
+        };
+        let x = chunk ^ broadcast;
+        let has_zero_byte = x.wrapping_sub(LO_U8) & !x & HI_U8;
+        if has_zero_byte != 0 {
+            let mut lane = 0;
+            while lane < word_size {
+                let value = unsafe { *data_ptr.add(index + lane) };
+                if value == key {
+                    return index + lane;
+                }
+                lane += 1;
+            }
+        }
+        index += word_size;
+    }
+    while index < data_length {
+        let value = unsafe { *data_ptr.add(index) };
+        if value == key {
+            return index;
+        }
+        index += 1;
+    }
+    return not_found_value;
+}
+
+/// Dispatch between [`search_small_u8`] and [`bsearch`] depending on `data_length`: below
+/// `cutoff`, the word-at-a-time scan in `search_small_u8` beats a binary search on short
+/// lookup tables; at or above it, `data_ptr` is expected to be sorted and `bsearch` wins by
+/// avoiding a full scan.
+///
+/// # Arguments
+///
+/// * `key` - Value to search for
+/// * `data_ptr` - Constant raw pointer to the array. You can get it using 'data_array.as_ptr()'
+/// * `data_length` - Array size. It can be lower than the array capacity.
+/// * `not_found_value` - Value to return if value not found
+/// * `cutoff` - Below this `data_length`, use `search_small_u8` instead of `bsearch`.
+///
+/// # Example
+///
+/// ```
+/// use rselib::sort::hybrid_search;
+///
+/// let test_array: [u8; 4] = [0x10, 0x20, 0x30, 0x40];
+/// let not_found_value: usize = 0xFFFF_FFFF;
+/// let found_index = unsafe {
+///     hybrid_search(
+///         0x30
+///         , test_array.as_ptr()
+///         , test_array.len()
+///         , not_found_value
+///         , 16
+///     )
+/// };
+/// assert_eq!(2, found_index);
+/// ```
+///
+/// # Safety
+/// `data_ptr` must be valid for reads of `data_length` bytes, same as [`search_small_u8`].
+pub unsafe fn hybrid_search(
+    key: u8
+    , data_ptr: *const u8
+    , data_length: usize
+    , not_found_value: usize
+    , cutoff: usize
+) -> usize
+{
+    if data_length < cutoff {
+        return unsafe {
+            search_small_u8(key, data_ptr, data_length, not_found_value)
+        };
+    }
+    return bsearch(
+        key
+        , data_ptr
+        , data_length
+        , not_found_value
+        , |key, ptr, index| {
+            let current_value = unsafe {
+                & *(
+                    ptr.add(index)
+                )
+            };
+            if *key == *current_value {
+                Ordering::Equal
+            } else if *key > *current_value {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            }
+        }
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cmp::Ordering;
+
+    /// Function to compare u8's used in aprox_bsearch
+    fn u8_cmp(
+        key: &u8
+        , ptr: *const u8
+        , index: usize
+    ) -> Ordering {
+        let current_value = unsafe {
+            & *(
+                ptr.add(index)
+            )
+        };
+
+        if *key == *current_value {
+            return Ordering::Equal
+        } else if *key > *current_value {
+            return Ordering::Greater;
+        } else {
+            return Ordering::Less;
+        }
+    }
+
+    fn u8_cp(
+        ptr: *mut u8
+        , src_index: usize
+        , dest_index: usize
+    ) {
+        let src = unsafe {
+            & *(
+                ptr.add(src_index)
+            )
+        };
+        let dest = unsafe {
+            &mut *(
+                ptr.add(dest_index)
+            )
+        };
+        *dest = *src;
+    }
+
+    #[test]
+    fn branchless_finds_value_in_the_middle() {
+        let test_array: [u8; 5] = [0x10, 0x20, 0x30, 0x40, 0x50];
+        let not_found_value: usize = 0xFFFF_FFFF;
+
+        let found_index = bsearch_branchless(
+            0x30
+            , test_array.as_ptr()
+            , test_array.len()
+            , not_found_value
+            , u8_cmp
+        );
+
+        assert_eq!(2, found_index);
+    }
+
+    #[test]
+    fn branchless_finds_first_and_last_element() {
+        let test_array: [u8; 3] = [0x10, 0x20, 0x30];
+        let not_found_value: usize = 0xFFFF_FFFF;
+
+        assert_eq!(0, bsearch_branchless(0x10, test_array.as_ptr(), test_array.len(), not_found_value, u8_cmp));
+        assert_eq!(2, bsearch_branchless(0x30, test_array.as_ptr(), test_array.len(), not_found_value, u8_cmp));
+    }
+
+    #[test]
+    fn branchless_single_element_array() {
+        let test_array: [u8; 1] = [0x10];
+        let not_found_value: usize = 0xFFFF_FFFF;
+
+        assert_eq!(0, bsearch_branchless(0x10, test_array.as_ptr(), test_array.len(), not_found_value, u8_cmp));
+        assert_eq!(not_found_value, bsearch_branchless(0x20, test_array.as_ptr(), test_array.len(), not_found_value, u8_cmp));
+    }
+
+    #[test]
+    fn branchless_empty_array() {
+        let test_array: [u8; 0] = [];
+        let not_found_value: usize = 0xFFFF_FFFF;
+
+        assert_eq!(
+            not_found_value
+            , bsearch_branchless(0x10, test_array.as_ptr(), test_array.len(), not_found_value, u8_cmp)
+        );
+    }
+
+    #[test]
+    fn branchless_value_below_and_above_range() {
+        let test_array: [u8; 3] = [0x10, 0x20, 0x30];
+        let not_found_value: usize = 0xFFFF_FFFF;
+
+        assert_eq!(not_found_value, bsearch_branchless(0x05, test_array.as_ptr(), test_array.len(), not_found_value, u8_cmp));
+        assert_eq!(not_found_value, bsearch_branchless(0x40, test_array.as_ptr(), test_array.len(), not_found_value, u8_cmp));
+    }
+
+    #[test]
+    fn eytzinger_finds_every_value() {
+        let sorted_array: [u8; 7] = [0x10, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70];
+        let mut eytzinger_array: [u8; 7] = [0; 7];
+
+        eytzinger_build(sorted_array.as_ptr(), eytzinger_array.as_mut_ptr(), sorted_array.len());
+
+        let not_found_value: usize = 0xFFFF_FFFF;
+        for &value in sorted_array.iter() {
+            let found_index = eytzinger_search(
+                value
+                , eytzinger_array.as_ptr()
+                , eytzinger_array.len()
+                , not_found_value
+                , u8_cmp
+            );
+            assert_eq!(eytzinger_array[found_index], value);
+        }
+    }
+
+    #[test]
+    fn eytzinger_value_below_and_above_range() {
+        let sorted_array: [u8; 3] = [0x10, 0x20, 0x30];
+        let mut eytzinger_array: [u8; 3] = [0; 3];
+
+        eytzinger_build(sorted_array.as_ptr(), eytzinger_array.as_mut_ptr(), sorted_array.len());
+
+        let not_found_value: usize = 0xFFFF_FFFF;
+        assert_eq!(not_found_value, eytzinger_search(0x05, eytzinger_array.as_ptr(), eytzinger_array.len(), not_found_value, u8_cmp));
+        assert_eq!(not_found_value, eytzinger_search(0x40, eytzinger_array.as_ptr(), eytzinger_array.len(), not_found_value, u8_cmp));
+        assert_eq!(not_found_value, eytzinger_search(0x15, eytzinger_array.as_ptr(), eytzinger_array.len(), not_found_value, u8_cmp));
+    }
+
+    #[test]
+    fn eytzinger_single_element_array() {
+        let sorted_array: [u8; 1] = [0x10];
+        let mut eytzinger_array: [u8; 1] = [0; 1];
+
+        eytzinger_build(sorted_array.as_ptr(), eytzinger_array.as_mut_ptr(), sorted_array.len());
+
+        let not_found_value: usize = 0xFFFF_FFFF;
+        assert_eq!(0, eytzinger_search(0x10, eytzinger_array.as_ptr(), eytzinger_array.len(), not_found_value, u8_cmp));
+        assert_eq!(not_found_value, eytzinger_search(0x20, eytzinger_array.as_ptr(), eytzinger_array.len(), not_found_value, u8_cmp));
+    }
+
+    #[test]
+    fn eytzinger_empty_array() {
+        let sorted_array: [u8; 0] = [];
+        let mut eytzinger_array: [u8; 0] = [];
+
+        eytzinger_build(sorted_array.as_ptr(), eytzinger_array.as_mut_ptr(), sorted_array.len());
+
+        let not_found_value: usize = 0xFFFF_FFFF;
+        assert_eq!(not_found_value, eytzinger_search(0x10, eytzinger_array.as_ptr(), eytzinger_array.len(), not_found_value, u8_cmp));
+    }
+
+    #[test]
+    fn bounds_with_duplicate_keys() {
+        let test_array: [u8; 8] = [0x01, 0x03, 0x03, 0x03, 0x05, 0x05, 0x07, 0x09];
+        let test_array_ptr = test_array.as_ptr();
+        let length = test_array.len();
+
+        assert_eq!(1, lower_bound(0x03, test_array_ptr, length, u8_cmp));
+        assert_eq!(4, upper_bound(0x03, test_array_ptr, length, u8_cmp));
+
+        assert_eq!(4, lower_bound(0x05, test_array_ptr, length, u8_cmp));
+        assert_eq!(6, upper_bound(0x05, test_array_ptr, length, u8_cmp));
+
+        let (start, end) = equal_range(0x03, test_array_ptr, length, u8_cmp);
+        assert_eq!((1, 4), (start, end));
+    }
+
+    #[test]
+    fn bounds_value_not_present() {
+        let test_array: [u8; 4] = [0x01, 0x03, 0x05, 0x07];
+        let test_array_ptr = test_array.as_ptr();
+        let length = test_array.len();
+
+        // Insertion point between existing values
+        assert_eq!(2, lower_bound(0x04, test_array_ptr, length, u8_cmp));
+        assert_eq!(2, upper_bound(0x04, test_array_ptr, length, u8_cmp));
+        assert_eq!((2, 2), equal_range(0x04, test_array_ptr, length, u8_cmp));
+    }
+
+    #[test]
+    fn bounds_below_and_above_range() {
+        let test_array: [u8; 3] = [0x10, 0x20, 0x30];
+        let test_array_ptr = test_array.as_ptr();
+        let length = test_array.len();
+
+        assert_eq!(0, lower_bound(0x00, test_array_ptr, length, u8_cmp));
+        assert_eq!(0, upper_bound(0x00, test_array_ptr, length, u8_cmp));
+
+        assert_eq!(3, lower_bound(0xFF, test_array_ptr, length, u8_cmp));
+        assert_eq!(3, upper_bound(0xFF, test_array_ptr, length, u8_cmp));
+    }
+
+    #[test]
+    fn bounds_on_empty_array() {
+        let test_array: [u8; 0] = [];
+        let test_array_ptr = test_array.as_ptr();
+        let length = test_array.len();
+
+        assert_eq!(0, lower_bound(0x10, test_array_ptr, length, u8_cmp));
+        assert_eq!(0, upper_bound(0x10, test_array_ptr, length, u8_cmp));
+        assert_eq!((0, 0), equal_range(0x10, test_array_ptr, length, u8_cmp));
+    }
+
+    #[test]
+    fn find_action_collects_all_matching_duplicate_keys() {
+        let test_array: [u8; 5] = [0x10, 0x20, 0x20, 0x20, 0x30];
+        let test_array_ptr = test_array.as_ptr();
+        let length = test_array.len();
+
+        let mut visited: Vec<usize> = Vec::new();
+        find_action(0x20, test_array_ptr, length, u8_cmp, |_ptr, index| {
+            visited.push(index);
+            true
+        });
+        assert_eq!(vec![1, 2, 3], visited);
+    }
+
+    #[test]
+    fn find_action_stops_early_when_action_returns_false() {
+        let test_array: [u8; 5] = [0x10, 0x20, 0x20, 0x20, 0x30];
+        let test_array_ptr = test_array.as_ptr();
+        let length = test_array.len();
+
+        let mut visited: Vec<usize> = Vec::new();
+        find_action(0x20, test_array_ptr, length, u8_cmp, |_ptr, index| {
+            visited.push(index);
+            false
+        });
+        assert_eq!(vec![1], visited);
+    }
+
+    #[test]
+    fn find_action_single_element_match() {
+        let test_array: [u8; 1] = [0x10];
+        let test_array_ptr = test_array.as_ptr();
+        let length = test_array.len();
+
+        let mut count: usize = 0;
+        find_action(0x10, test_array_ptr, length, u8_cmp, |_ptr, _index| {
+            count += 1;
+            true
+        });
+        assert_eq!(1, count);
+    }
+
+    #[test]
+    fn find_action_key_below_and_above_range() {
+        let test_array: [u8; 3] = [0x10, 0x20, 0x30];
+        let test_array_ptr = test_array.as_ptr();
+        let length = test_array.len();
+
+        let mut count: usize = 0;
+        find_action(0x00, test_array_ptr, length, u8_cmp, |_ptr, _index| {
+            count += 1;
+            true
+        });
+        assert_eq!(0, count);
+
+        find_action(0xFF, test_array_ptr, length, u8_cmp, |_ptr, _index| {
+            count += 1;
+            true
+        });
+        assert_eq!(0, count);
+    }
+
+    #[test]
+    fn find_action_on_empty_array() {
+        let test_array: [u8; 0] = [];
+        let test_array_ptr = test_array.as_ptr();
+        let length = test_array.len();
+
+        let mut count: usize = 0;
+        find_action(0x10, test_array_ptr, length, u8_cmp, |_ptr, _index| {
+            count += 1;
+            true
+        });
+        assert_eq!(0, count);
+    }
+
+    #[test]
+    fn insert_at_the_end() {
+        let mut test_array: [u8; 3] = [0x10, 0x20, 0x00]; // pre allocated array
         let new_value: u8 = 0x25;
         let not_allocated_value: usize = 0xFFFF_FFFF;
 
@@ -463,4 +1522,287 @@ mod tests {
         assert_eq!(0x20, test_array[1]);
         assert_eq!(0, added_count);
     }
+
+    #[test]
+    // `test_array` is only ever mutated through `test_array_ptr` inside `sorted_array_remove`,
+    // which is why `test_array_ptr` is obtained via `as_mut_ptr()` below rather than
+    // `as_ptr() as *mut u8` (the latter derives the pointer from a shared reference, and
+    // writing through it is UB even when the binding itself is `mut` -- this is optimized
+    // away in some builds but reproducibly miscompiles in release).
+    fn remove_middle_value() {
+        let mut test_array: [u8; 3] = [0x10, 0x20, 0x30];
+
+        let test_array_ptr = test_array.as_mut_ptr();
+        let mut length = 3; // Used elements in the array
+        let (res, removed_index, removed_count) = sorted_array_remove(
+            0x20
+            , test_array_ptr
+            , length
+            , u8_cmp
+            , u8_cp
+        );
+
+        assert!(matches!(res, RemoveResult::Ok));
+        assert_eq!(1, removed_index);
+        assert_eq!(1, removed_count);
+
+        // !!Dont forget to update the used length
+        length -= removed_count;
+
+        assert_eq!(0x10, test_array[0]);
+        assert_eq!(0x30, test_array[1]);
+        assert_eq!(2, length);
+    }
+
+    #[test]
+    // See the comment on `remove_middle_value`: `as_mut_ptr()` is required for soundness.
+    fn remove_last_value() {
+        let mut test_array: [u8; 3] = [0x10, 0x20, 0x30];
+
+        let test_array_ptr = test_array.as_mut_ptr();
+        let mut length = 3; // Used elements in the array
+        let (res, removed_index, removed_count) = sorted_array_remove(
+            0x30
+            , test_array_ptr
+            , length
+            , u8_cmp
+            , u8_cp
+        );
+
+        assert!(matches!(res, RemoveResult::Ok));
+        assert_eq!(2, removed_index);
+        assert_eq!(1, removed_count);
+
+        length -= removed_count;
+
+        assert_eq!(0x10, test_array[0]);
+        assert_eq!(0x20, test_array[1]);
+        assert_eq!(2, length);
+    }
+
+    #[test]
+    // See the comment on `remove_middle_value`: kept `mut`/`as_mut_ptr()` for consistency with
+    // the other `sorted_array_remove` tests, even though this particular case never writes.
+    fn remove_value_not_found() {
+        let mut test_array: [u8; 3] = [0x10, 0x20, 0x30];
+
+        let test_array_ptr = test_array.as_mut_ptr();
+        let length = 3; // Used elements in the array
+        let (res, _removed_index, removed_count) = sorted_array_remove(
+            0x15
+            , test_array_ptr
+            , length
+            , u8_cmp
+            , u8_cp
+        );
+
+        assert!(matches!(res, RemoveResult::NotFound));
+        assert_eq!(0, removed_count);
+
+        assert_eq!(0x10, test_array[0]);
+        assert_eq!(0x20, test_array[1]);
+        assert_eq!(0x30, test_array[2]);
+    }
+
+    fn u8_sort(ptr: *mut u8, length: usize) {
+        let slice = unsafe {
+            core::slice::from_raw_parts_mut(ptr, length)
+        };
+        slice.sort_unstable();
+    }
+
+    #[test]
+    fn sorted_array_push_unsorted_then_search() {
+        let mut buffer: [u8; 4] = [0; 4];
+        let mut sorted_array = SortedArray::new(
+            buffer.as_mut_ptr()
+            , 0
+            , buffer.len()
+            , true
+        );
+
+        assert!(sorted_array.push_unsorted(0x30));
+        assert!(sorted_array.push_unsorted(0x10));
+        assert!(sorted_array.push_unsorted(0x20));
+        assert_eq!(3, sorted_array.len());
+        assert_eq!(1, sorted_array.remaining());
+
+        let not_found_value: usize = 0xFFFF_FFFF;
+        let found_index = sorted_array.search(0x20, not_found_value, u8_cmp, u8_sort);
+
+        assert_eq!(buffer[found_index], 0x20);
+        assert_eq!(0x10, buffer[0]);
+        assert_eq!(0x20, buffer[1]);
+        assert_eq!(0x30, buffer[2]);
+    }
+
+    #[test]
+    fn sorted_array_insert_keeps_order() {
+        let mut buffer: [u8; 3] = [0x10, 0x30, 0x00];
+        let mut sorted_array = SortedArray::new(
+            buffer.as_mut_ptr()
+            , 2
+            , buffer.len()
+            , true
+        );
+
+        let not_allocated_value: usize = 0xFFFF_FFFF;
+        let (res, possible_index, added_count) = sorted_array.insert(
+            0x20
+            , not_allocated_value
+            , u8_cmp
+            , u8_cp
+        );
+
+        assert!(matches!(res, SortedArrayAllocResult::Ok));
+        assert_eq!(1, possible_index);
+        assert_eq!(1, added_count);
+        assert_eq!(3, sorted_array.len());
+
+        assert_eq!(0x10, buffer[0]);
+        assert_eq!(0x20, buffer[1]);
+        assert_eq!(0x30, buffer[2]);
+
+        assert!(sorted_array.contains(0x20, u8_cmp, u8_sort));
+        assert!(!sorted_array.contains(0x15, u8_cmp, u8_sort));
+    }
+
+    #[test]
+    fn sorted_array_insert_while_dirty_does_not_duplicate_existing_key() {
+        let mut buffer: [u8; 4] = [0; 4];
+        let mut sorted_array = SortedArray::new(
+            buffer.as_mut_ptr()
+            , 0
+            , buffer.len()
+            , true
+        );
+
+        assert!(sorted_array.push_unsorted(0x10));
+
+        let not_allocated_value: usize = 0xFFFF_FFFF;
+        let (res, possible_index, added_count) = sorted_array.insert(
+            0x10
+            , not_allocated_value
+            , u8_cmp
+            , u8_cp
+        );
+
+        assert!(matches!(res, SortedArrayAllocResult::Ok));
+        assert_eq!(0, possible_index);
+        assert_eq!(0, added_count);
+        assert_eq!(1, sorted_array.len());
+    }
+
+    #[test]
+    fn sorted_array_insert_while_sorted_does_not_overwrite_existing_key() {
+        let mut buffer: [u8; 3] = [0x10, 0x20, 0x30];
+        let mut sorted_array = SortedArray::new(
+            buffer.as_mut_ptr()
+            , 3
+            , buffer.len()
+            , true
+        );
+
+        let not_allocated_value: usize = 0xFFFF_FFFF;
+        let (res, possible_index, added_count) = sorted_array.insert(
+            0x20
+            , not_allocated_value
+            , u8_cmp
+            , u8_cp
+        );
+
+        assert!(matches!(res, SortedArrayAllocResult::Ok));
+        assert_eq!(1, possible_index);
+        assert_eq!(0, added_count);
+        assert_eq!(3, sorted_array.len());
+
+        assert_eq!(0x10, buffer[0]);
+        assert_eq!(0x20, buffer[1]);
+        assert_eq!(0x30, buffer[2]);
+    }
+
+    #[test]
+    fn sorted_array_is_empty() {
+        let mut buffer: [u8; 2] = [0; 2];
+        let mut sorted_array = SortedArray::new(
+            buffer.as_mut_ptr()
+            , 0
+            , buffer.len()
+            , true
+        );
+
+        assert!(sorted_array.is_empty());
+        assert!(sorted_array.push_unsorted(0x10));
+        assert!(!sorted_array.is_empty());
+    }
+
+    #[test]
+    fn search_small_u8_finds_value_in_unaligned_tail() {
+        let test_array: [u8; 5] = [0x10, 0x20, 0x30, 0x40, 0x50];
+        let not_found_value: usize = 0xFFFF_FFFF;
+
+        let found_index = unsafe {
+            search_small_u8(
+                0x50
+                , test_array.as_ptr()
+                , test_array.len()
+                , not_found_value
+            )
+        };
+
+        assert_eq!(4, found_index);
+    }
+
+    #[test]
+    fn search_small_u8_value_not_found() {
+        let test_array: [u8; 5] = [0x10, 0x20, 0x30, 0x40, 0x50];
+        let not_found_value: usize = 0xFFFF_FFFF;
+
+        let found_index = unsafe {
+            search_small_u8(
+                0x15
+                , test_array.as_ptr()
+                , test_array.len()
+                , not_found_value
+            )
+        };
+
+        assert_eq!(not_found_value, found_index);
+    }
+
+    #[test]
+    fn hybrid_search_uses_small_scan_below_cutoff() {
+        let test_array: [u8; 4] = [0x10, 0x20, 0x30, 0x40];
+        let not_found_value: usize = 0xFFFF_FFFF;
+
+        let found_index = unsafe {
+            hybrid_search(
+                0x30
+                , test_array.as_ptr()
+                , test_array.len()
+                , not_found_value
+                , 16
+            )
+        };
+
+        assert_eq!(2, found_index);
+    }
+
+    #[test]
+    fn hybrid_search_uses_bsearch_above_cutoff() {
+        let test_array: [u8; 4] = [0x10, 0x20, 0x30, 0x40];
+        let not_found_value: usize = 0xFFFF_FFFF;
+
+        let found_index = unsafe {
+            hybrid_search(
+                0x30
+                , test_array.as_ptr()
+                , test_array.len()
+                , not_found_value
+                , 0
+            )
+        };
+
+        assert_eq!(2, found_index);
+    }
 }
\ No newline at end of file